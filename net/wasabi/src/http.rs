@@ -2,9 +2,12 @@ extern crate alloc;
 use crate::alloc::string::ToString;
 use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
 use noli::net::lookup_host;
-// use saba_core::error::Error;  Todo: Create a custom error type
-// use saba_core::http::HttpResponse;  // Todo: Create a custom HttpResponse type
+use noli::net::SocketAddr;
+use noli::net::TcpStream;
+use saba_core::error::Error;
+use saba_core::http::HttpResponse;
 
 pub struct HttpClient {}
 
@@ -13,7 +16,13 @@ impl HttpClient {
         Self {}
     }
 
-    pub fn get(&self, host: String, port: u16, path: String) -> Result<HttpResponse, Error> {
+    pub fn get(
+        &self,
+        host: String,
+        port: u16,
+        path: String,
+        searchpart: String,
+    ) -> Result<HttpResponse, Error> {
         let ips = match lookup_host(&host) {
             Ok(ips) => ips,
             Err(e) => {
@@ -27,6 +36,56 @@ impl HttpClient {
         if ips.len() < 1 {
             return Err(Error::Network("Failed to find IP addresses".to_string()));
         }
+
+        let socket_addr: SocketAddr = (ips[0], port).into();
+
+        let mut stream = match TcpStream::connect(socket_addr) {
+            Ok(stream) => stream,
+            Err(_) => {
+                return Err(Error::Network(
+                    "Failed to connect to TCP stream".to_string(),
+                ))
+            }
+        };
+
+        let request = format!(
+            "GET {}?{} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+            path, searchpart, host
+        );
+
+        let _bytes_written = match stream.write(request.as_bytes()) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                return Err(Error::Network(
+                    "Failed to send a request to TCP stream".to_string(),
+                ))
+            }
+        };
+
+        let mut received = Vec::new();
+        loop {
+            let mut buf = [0u8; 4096];
+            let bytes_read = match stream.read(&mut buf) {
+                Ok(bytes) => bytes,
+                Err(_) => {
+                    return Err(Error::Network(
+                        "Failed to receive a response from TCP stream".to_string(),
+                    ))
+                }
+            };
+            if bytes_read == 0 {
+                break;
+            }
+            received.extend_from_slice(&buf[..bytes_read]);
+        }
+
+        match core::str::from_utf8(&received) {
+            Ok(response) => HttpResponse::new(response.to_string()),
+            Err(e) => Err(Error::InvalidResponse(format!(
+                "Invalid received response: {}",
+                e
+            ))),
+        }
     }
 }
 
@@ -0,0 +1,10 @@
+use alloc::string::String;
+
+/// saba_core全体で使われるエラー型です
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// ソケットの接続や送受信に失敗した場合のエラー
+    Network(String),
+    /// レスポンスの形式が不正で解釈できない場合のエラー
+    InvalidResponse(String),
+}
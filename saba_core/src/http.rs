@@ -0,0 +1,150 @@
+use crate::error::Error;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// HTTPレスポンスを表します
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    version: String,
+    status_code: u16,
+    reason: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl HttpResponse {
+    /// 生のHTTPレスポンス文字列を解析します
+    pub fn new(raw_response: String) -> Result<Self, Error> {
+        let (header_block, body) = match raw_response.split_once("\r\n\r\n") {
+            Some((header_block, body)) => (header_block, body.to_string()),
+            None => (raw_response.as_str(), String::new()),
+        };
+
+        let mut lines = header_block.lines();
+        let status_line = lines
+            .next()
+            .ok_or_else(|| Error::InvalidResponse("No status line found".to_string()))?;
+
+        let status_parts: Vec<&str> = status_line.splitn(3, ' ').collect();
+        if status_parts.len() != 3 {
+            return Err(Error::InvalidResponse(format!(
+                "Invalid status line: {}",
+                status_line
+            )));
+        }
+        let version = status_parts[0].to_string();
+        let status_code = status_parts[1].parse::<u16>().map_err(|_| {
+            Error::InvalidResponse(format!("Invalid status code: {}", status_parts[1]))
+        })?;
+        let reason = status_parts[2].to_string();
+
+        let mut headers = Vec::new();
+        for line in lines {
+            if let Some((name, value)) = line.split_once(": ") {
+                headers.push((name.to_string(), value.to_string()));
+            }
+        }
+
+        Ok(Self {
+            version,
+            status_code,
+            reason,
+            headers,
+            body,
+        })
+    }
+
+    /// HTTPバージョンを取得します
+    pub fn version(&self) -> String {
+        self.version.clone()
+    }
+
+    /// ステータスコードを取得します
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// ステータスの理由句を取得します
+    pub fn reason(&self) -> String {
+        self.reason.clone()
+    }
+
+    /// レスポンスヘッダーの一覧を取得します
+    pub fn headers(&self) -> Vec<(String, String)> {
+        self.headers.clone()
+    }
+
+    /// ヘッダーの値を大文字小文字を区別せずに取得します
+    pub fn header_value(&self, name: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    }
+
+    /// レスポンスボディを取得します
+    pub fn body(&self) -> String {
+        self.body.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// ステータス行・ヘッダー・ボディを正しく解析できるか
+    #[test]
+    fn test_parse_response() {
+        let raw = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 5\r\n\r\nhello"
+            .to_string();
+        let response = HttpResponse::new(raw).expect("should parse");
+
+        assert_eq!("HTTP/1.1", response.version());
+        assert_eq!(200, response.status_code());
+        assert_eq!("OK", response.reason());
+        assert_eq!(
+            vec![
+                ("Content-Type".to_string(), "text/html".to_string()),
+                ("Content-Length".to_string(), "5".to_string()),
+            ],
+            response.headers()
+        );
+        assert_eq!("hello", response.body());
+    }
+
+    /// ヘッダー名の大文字小文字を区別せずに値を取得できるか
+    #[test]
+    fn test_header_value_case_insensitive() {
+        let raw = "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n".to_string();
+        let response = HttpResponse::new(raw).expect("should parse");
+
+        assert_eq!(
+            Some("text/html".to_string()),
+            response.header_value("content-type")
+        );
+        assert_eq!(None, response.header_value("Content-Length"));
+    }
+
+    /// ボディを伴わないレスポンスでも解析できるか
+    #[test]
+    fn test_parse_response_without_body() {
+        let raw = "HTTP/1.1 204 No Content\r\n\r\n".to_string();
+        let response = HttpResponse::new(raw).expect("should parse");
+
+        assert_eq!(204, response.status_code());
+        assert_eq!("", response.body());
+    }
+
+    /// ステータス行が不正な場合にエラーを返すか
+    #[test]
+    fn test_parse_invalid_status_line() {
+        let raw = "malformed\r\n\r\n".to_string();
+        let expected = Err(Error::InvalidResponse(
+            "Invalid status line: malformed".to_string(),
+        ));
+
+        assert_eq!(expected, HttpResponse::new(raw));
+    }
+}
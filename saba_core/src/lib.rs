@@ -0,0 +1,7 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod error;
+pub mod http;
+pub mod url;